@@ -1,18 +1,23 @@
 #![doc = include_str!("../README.md")]
 
 use std::{
+    any::Any,
+    cell::RefCell,
     future::Future,
     hint::spin_loop,
+    panic::{catch_unwind, AssertUnwindSafe},
     pin::*,
     sync::{
-        atomic::{AtomicU8, AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering},
         Arc,
     },
     task::*,
     thread::{available_parallelism, yield_now, Thread},
+    time::{Duration, Instant},
 };
 
 use branches::{likely, unlikely};
+use futures_core::Stream;
 
 thread_local! {
     // A reusable signal instance per thread.
@@ -37,10 +42,87 @@ pub trait FutureExt: Future {
     {
         swait(self)
     }
+
+    /// Blocks the current thread until the future is ready or `timeout` elapses,
+    /// whichever comes first.
+    ///
+    /// Returns `None` if the deadline passes before the future resolves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use swait::FutureExt;
+    /// let my_fut = async {};
+    /// let result = my_fut.swait_timeout(Duration::from_millis(10));
+    /// assert_eq!(result, Some(()));
+    /// ```
+    #[inline(always)]
+    fn swait_timeout(self, timeout: Duration) -> Option<Self::Output>
+    where
+        Self: Sized,
+    {
+        swait_timeout(self, timeout)
+    }
+
+    /// Blocks the current thread until the future is ready, catching a panic
+    /// from any of its polls instead of letting it unwind through the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use swait::FutureExt;
+    /// let my_fut = async {};
+    /// let result = my_fut.try_swait();
+    /// assert_eq!(result.unwrap(), ());
+    /// ```
+    #[inline(always)]
+    fn try_swait(self) -> Result<Self::Output, Box<dyn Any + Send>>
+    where
+        Self: Sized,
+    {
+        try_swait(self)
+    }
 }
 
 impl<F: Future> FutureExt for F {}
 
+/// Extension trait for blocking on a [`Stream`]'s items.
+pub trait SwaitStreamExt: Stream {
+    /// Blocks the current thread until the stream yields its next item, or
+    /// returns `None` once the stream reports end-of-stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_lite::stream;
+    /// use swait::SwaitStreamExt;
+    /// let mut my_stream = stream::iter(vec![1, 2]);
+    /// assert_eq!(my_stream.snext(), Some(1));
+    /// assert_eq!(my_stream.snext(), Some(2));
+    /// assert_eq!(my_stream.snext(), None);
+    /// ```
+    #[inline(always)]
+    fn snext(&mut self) -> Option<Self::Item>
+    where
+        Self: Sized + Unpin,
+    {
+        snext(self)
+    }
+
+    /// Turns the stream into a blocking [`Iterator`] that drives it to
+    /// completion, one item at a time, on the current thread.
+    #[inline(always)]
+    fn swait_iter(self) -> SwaitIter<Self>
+    where
+        Self: Sized + Unpin,
+    {
+        SwaitIter { stream: self }
+    }
+}
+
+impl<S: Stream> SwaitStreamExt for S {}
+
 const WAITING: u8 = 0;
 const PARKED: u8 = 1;
 const NOTIFIED: u8 = 255;
@@ -92,6 +174,53 @@ impl Signal {
             self.owning_thread.unpark();
         }
     }
+
+    /// Like [`Signal::wait`], but gives up once `deadline` has passed.
+    ///
+    /// Returns `true` if a notification was observed before the deadline, or
+    /// `false` if the deadline elapsed first. On timeout the state is reset to
+    /// `WAITING` so the thread-local signal remains reusable for later calls.
+    fn wait_timeout(&self, deadline: Instant) -> bool {
+        if likely(cond_spin_timeout(
+            || {
+                self.state
+                    .compare_exchange(NOTIFIED, WAITING, Ordering::AcqRel, Ordering::Relaxed)
+                    .is_ok()
+            },
+            deadline,
+        )) {
+            return true;
+        }
+        if likely(
+            self.state
+                .compare_exchange(WAITING, PARKED, Ordering::AcqRel, Ordering::Relaxed)
+                .is_err(),
+        ) {
+            // already notified, reset state to waiting
+            self.state.store(WAITING, Ordering::Release);
+            return true;
+        }
+        loop {
+            if self
+                .state
+                .compare_exchange(NOTIFIED, WAITING, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                return true;
+            }
+            let now = Instant::now();
+            if unlikely(now >= deadline) {
+                // Give up and reset PARKED back to WAITING so the signal stays
+                // reusable; if a notification races in right after this, it will
+                // simply be observed by the next wait call.
+                self.state
+                    .compare_exchange(PARKED, WAITING, Ordering::AcqRel, Ordering::Relaxed)
+                    .ok();
+                return false;
+            }
+            std::thread::park_timeout(deadline - now);
+        }
+    }
 }
 
 impl Wake for Signal {
@@ -105,6 +234,74 @@ impl Wake for Signal {
     }
 }
 
+/// A waker shared by the small cooperative schedulers (`swait_join`,
+/// `swait_all`, ...) that drive more than one future on the calling thread.
+///
+/// Waking it marks `scheduled` so the scheduler knows to re-poll that
+/// particular future, then notifies the owning thread's [`Signal`] so a
+/// parked scheduler loop wakes up to act on it.
+struct TaskWaker {
+    scheduled: Arc<AtomicBool>,
+    signal: Arc<Signal>,
+}
+
+impl Wake for TaskWaker {
+    #[inline(always)]
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+    #[inline(always)]
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.scheduled.store(true, Ordering::Release);
+        self.signal.notify();
+    }
+}
+
+/// One future tracked by a cooperative scheduler, alongside the flag and
+/// waker used to know when it is worth re-polling. `F` may be unsized (e.g.
+/// `dyn Future<Output = ()>`) for schedulers that box tasks of varying
+/// concrete types.
+struct Task<F: Future + ?Sized> {
+    scheduled: Arc<AtomicBool>,
+    waker: Waker,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Task<F> {
+    /// Pins `future` and gives it its own waker, wired to flag `scheduled`
+    /// and notify `signal` when woken. Tasks start out scheduled so they get
+    /// polled at least once before anything can wake them.
+    fn new(future: F, signal: &Arc<Signal>) -> Self {
+        Self::from_boxed(Box::pin(future), signal)
+    }
+}
+
+impl<F: Future + ?Sized> Task<F> {
+    /// Like [`Task::new`], but for a future that is already boxed and
+    /// possibly unsized.
+    fn from_boxed(future: Pin<Box<F>>, signal: &Arc<Signal>) -> Self {
+        let scheduled = Arc::new(AtomicBool::new(true));
+        let waker = Waker::from(Arc::new(TaskWaker {
+            scheduled: Arc::clone(&scheduled),
+            signal: Arc::clone(signal),
+        }));
+        Self {
+            future,
+            scheduled,
+            waker,
+        }
+    }
+
+    /// Polls the future if (and only if) it is currently scheduled, clearing
+    /// the flag first so a wake during the poll reschedules it.
+    fn poll_if_scheduled(&mut self, context: &mut Context<'_>) -> Option<Poll<F::Output>> {
+        if !self.scheduled.swap(false, Ordering::AcqRel) {
+            return None;
+        }
+        Some(self.future.as_mut().poll(context))
+    }
+}
+
 #[inline(always)]
 fn is_multithreaded_env() -> bool {
     static PARRALLELISM: AtomicUsize = AtomicUsize::new(0);
@@ -166,6 +363,51 @@ fn cond_spin(predicate: impl Fn() -> bool) -> bool {
     return false;
 }
 
+/// Like [`cond_spin`], but also bails out once `deadline` has passed, in which
+/// case the predicate may still be unmet. This keeps the spin/yield phase of a
+/// timed wait from overshooting a short deadline.
+#[inline(always)]
+fn cond_spin_timeout(predicate: impl Fn() -> bool, deadline: Instant) -> bool {
+    if likely(predicate()) {
+        return true;
+    }
+    const SPINING_COUNT: usize = 5;
+    const YIELD_COUNT: usize = 5;
+    if is_multithreaded_env() {
+        for shift in 1..(1 + SPINING_COUNT) {
+            for _ in 0..1 << shift {
+                spin_loop();
+            }
+            if likely(predicate()) {
+                return true;
+            }
+            if unlikely(Instant::now() >= deadline) {
+                return false;
+            }
+        }
+        for _ in 0..YIELD_COUNT {
+            yield_now();
+            if likely(predicate()) {
+                return true;
+            }
+            if unlikely(Instant::now() >= deadline) {
+                return false;
+            }
+        }
+    } else {
+        for _ in 0..(YIELD_COUNT + SPINING_COUNT) {
+            yield_now();
+            if likely(predicate()) {
+                return true;
+            }
+            if unlikely(Instant::now() >= deadline) {
+                return false;
+            }
+        }
+    }
+    return false;
+}
+
 /// Blocks the current thread until the given future is ready.
 ///
 /// # Example
@@ -183,7 +425,7 @@ fn cond_spin(predicate: impl Fn() -> bool) -> bool {
 /// let result = my_fut.swait();
 /// ```
 #[inline(always)]
-pub fn swait<F: Future>(mut fut: F) -> F::Output {
+pub fn swait<F: Future>(fut: F) -> F::Output {
     let mut fut = pin!(fut);
     THREAD_SIGNAL.with(|signal| {
         let waker = Waker::from(Arc::clone(signal));
@@ -196,3 +438,441 @@ pub fn swait<F: Future>(mut fut: F) -> F::Output {
         }
     })
 }
+
+/// Blocks the current thread until the given future is ready or `timeout`
+/// elapses, whichever comes first.
+///
+/// Returns `None` if the deadline passes before the future resolves.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// let my_fut = async {};
+/// let result = swait::swait_timeout(my_fut, Duration::from_millis(10));
+/// assert_eq!(result, Some(()));
+/// ```
+#[inline(always)]
+pub fn swait_timeout<F: Future>(fut: F, timeout: Duration) -> Option<F::Output> {
+    let deadline = Instant::now() + timeout;
+    let mut fut = pin!(fut);
+    THREAD_SIGNAL.with(|signal| {
+        let waker = Waker::from(Arc::clone(signal));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match fut.as_mut().poll(&mut context) {
+                Poll::Ready(result) => return Some(result),
+                Poll::Pending => {
+                    if unlikely(!signal.wait_timeout(deadline) || Instant::now() >= deadline) {
+                        return None;
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Drives any number of same-typed futures to completion concurrently on the
+/// calling thread, without a runtime, returning their outputs in the order
+/// the futures were given.
+///
+/// Each future gets its own [`Waker`]; only futures woken since the last poll
+/// are re-polled, and the thread parks on the shared per-thread [`Signal`]
+/// whenever nothing is currently scheduled but futures remain pending. This
+/// lets you fan out several channel receives or I/O futures and block until
+/// all of them finish, which a plain loop of [`swait`] cannot do because it
+/// would serialize them.
+///
+/// # Example
+///
+/// ```
+/// async fn compute(n: i32) -> i32 {
+///     n
+/// }
+/// let results = swait::swait_all(vec![compute(1), compute(2), compute(3)]);
+/// assert_eq!(results, vec![1, 2, 3]);
+/// ```
+pub fn swait_all<F: Future>(futures: impl IntoIterator<Item = F>) -> Vec<F::Output> {
+    THREAD_SIGNAL.with(|signal| {
+        let mut tasks: Vec<Task<F>> = futures
+            .into_iter()
+            .map(|future| Task::new(future, signal))
+            .collect();
+        let mut results: Vec<Option<F::Output>> = tasks.iter().map(|_| None).collect();
+        let mut remaining = tasks.len();
+
+        while remaining > 0 {
+            let mut any_scheduled = false;
+            for (index, task) in tasks.iter_mut().enumerate() {
+                if results[index].is_some() {
+                    continue;
+                }
+                let waker = task.waker.clone();
+                let mut context = Context::from_waker(&waker);
+                if let Some(poll) = task.poll_if_scheduled(&mut context) {
+                    any_scheduled = true;
+                    if let Poll::Ready(output) = poll {
+                        results[index] = Some(output);
+                        remaining -= 1;
+                    }
+                }
+            }
+            if !any_scheduled && remaining > 0 {
+                signal.wait();
+            }
+        }
+
+        results.into_iter().map(Option::unwrap).collect()
+    })
+}
+
+/// Blocks until both of two possibly differently-typed futures resolve,
+/// returning both outputs once each has completed.
+///
+/// Built on the same per-future scheduled-flag plus shared [`Signal`] waker
+/// scheme as [`swait_all`]: each future is polled only when its own waker has
+/// fired, and the thread parks whenever neither future is currently
+/// scheduled. For more than two futures of the same type, use [`swait_all`].
+///
+/// # Example
+///
+/// ```
+/// let (a, b) = swait::swait_join(async { 1 }, async { "two" });
+/// assert_eq!(a, 1);
+/// assert_eq!(b, "two");
+/// ```
+pub fn swait_join<A: Future, B: Future>(a: A, b: B) -> (A::Output, B::Output) {
+    THREAD_SIGNAL.with(|signal| {
+        let mut a = Task::new(a, signal);
+        let mut b = Task::new(b, signal);
+        let mut a_result = None;
+        let mut b_result = None;
+
+        loop {
+            let mut any_scheduled = false;
+            if a_result.is_none() {
+                let waker = a.waker.clone();
+                let mut context = Context::from_waker(&waker);
+                if let Some(poll) = a.poll_if_scheduled(&mut context) {
+                    any_scheduled = true;
+                    if let Poll::Ready(output) = poll {
+                        a_result = Some(output);
+                    }
+                }
+            }
+            if b_result.is_none() {
+                let waker = b.waker.clone();
+                let mut context = Context::from_waker(&waker);
+                if let Some(poll) = b.poll_if_scheduled(&mut context) {
+                    any_scheduled = true;
+                    if let Poll::Ready(output) = poll {
+                        b_result = Some(output);
+                    }
+                }
+            }
+            match (a_result.take(), b_result.take()) {
+                (Some(a_output), Some(b_output)) => return (a_output, b_output),
+                (taken_a, taken_b) => {
+                    a_result = taken_a;
+                    b_result = taken_b;
+                }
+            }
+            if !any_scheduled {
+                signal.wait();
+            }
+        }
+    })
+}
+
+/// Blocks until the first of several same-typed futures resolves, returning
+/// its output along with the still-pending futures so they can be resumed
+/// later.
+///
+/// Built on the same per-future scheduled-flag plus shared [`Signal`] waker
+/// scheme as [`swait_all`]: each future gets a distinct [`Waker`] so a wake
+/// only marks the relevant future for re-polling. The main loop polls
+/// flagged futures and, on the first [`Poll::Ready`], drops the rest of the
+/// waker set and returns.
+///
+/// # Example
+///
+/// ```
+/// use std::future::Future;
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll};
+/// use std::thread;
+/// use std::time::{Duration, Instant};
+///
+/// // A future that resolves to `value` once `deadline` has passed, waking itself
+/// // from a background thread instead of blocking the polling thread.
+/// struct Delay {
+///     deadline: Instant,
+///     value: &'static str,
+///     waker_spawned: bool,
+/// }
+///
+/// impl Future for Delay {
+///     type Output = &'static str;
+///
+///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<&'static str> {
+///         if Instant::now() >= self.deadline {
+///             return Poll::Ready(self.value);
+///         }
+///         if !self.waker_spawned {
+///             self.waker_spawned = true;
+///             let waker = cx.waker().clone();
+///             let deadline = self.deadline;
+///             thread::spawn(move || {
+///                 let now = Instant::now();
+///                 if deadline > now {
+///                     thread::sleep(deadline - now);
+///                 }
+///                 waker.wake();
+///             });
+///         }
+///         Poll::Pending
+///     }
+/// }
+///
+/// let slow = Delay {
+///     deadline: Instant::now() + Duration::from_millis(30),
+///     value: "slow",
+///     waker_spawned: false,
+/// };
+/// let fast = Delay {
+///     deadline: Instant::now(),
+///     value: "fast",
+///     waker_spawned: false,
+/// };
+/// let (winner, _rest) = swait::swait_race(vec![slow, fast]);
+/// assert_eq!(winner, "fast");
+/// ```
+pub fn swait_race<F: Future>(
+    futures: impl IntoIterator<Item = F>,
+) -> (F::Output, Vec<Pin<Box<F>>>) {
+    THREAD_SIGNAL.with(|signal| {
+        let mut tasks: Vec<Task<F>> = futures
+            .into_iter()
+            .map(|future| Task::new(future, signal))
+            .collect();
+
+        loop {
+            let mut any_scheduled = false;
+            let mut ready = None;
+            for (index, task) in tasks.iter_mut().enumerate() {
+                let waker = task.waker.clone();
+                let mut context = Context::from_waker(&waker);
+                if let Some(poll) = task.poll_if_scheduled(&mut context) {
+                    any_scheduled = true;
+                    if let Poll::Ready(output) = poll {
+                        ready = Some((index, output));
+                        break;
+                    }
+                }
+            }
+            if let Some((index, output)) = ready {
+                let remaining = tasks
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .map(|(_, task)| task.future)
+                    .collect();
+                return (output, remaining);
+            }
+            if !any_scheduled {
+                signal.wait();
+            }
+        }
+    })
+}
+
+/// Blocks the current thread until `stream` yields its next item, or returns
+/// `None` once the stream reports end-of-stream.
+///
+/// Reuses the same thread-local [`Signal`] and spin/yield park strategy as
+/// [`swait`], looping on [`Stream::poll_next`] and parking on
+/// [`Poll::Pending`].
+///
+/// # Example
+///
+/// ```
+/// use futures_lite::stream;
+/// let mut my_stream = stream::iter(vec![1, 2]);
+/// assert_eq!(swait::snext(&mut my_stream), Some(1));
+/// assert_eq!(swait::snext(&mut my_stream), Some(2));
+/// assert_eq!(swait::snext(&mut my_stream), None);
+/// ```
+#[inline(always)]
+pub fn snext<S: Stream + Unpin>(stream: &mut S) -> Option<S::Item> {
+    let mut stream = Pin::new(stream);
+    THREAD_SIGNAL.with(|signal| {
+        let waker = Waker::from(Arc::clone(signal));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match stream.as_mut().poll_next(&mut context) {
+                Poll::Pending => signal.wait(),
+                Poll::Ready(item) => return item,
+            }
+        }
+    })
+}
+
+/// Blocking [`Iterator`] adapter returned by [`SwaitStreamExt::swait_iter`].
+pub struct SwaitIter<S> {
+    stream: S,
+}
+
+impl<S: Stream + Unpin> Iterator for SwaitIter<S> {
+    type Item = S::Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        snext(&mut self.stream)
+    }
+}
+
+/// Blocks the current thread until the given future is ready, catching a
+/// panic from any of its polls instead of letting it unwind through the
+/// caller.
+///
+/// This lets servers block on untrusted futures without tearing down the
+/// worker thread.
+///
+/// # Unwind safety
+///
+/// The pinned future and its polling [`Context`] are wrapped in
+/// [`AssertUnwindSafe`] for the duration of [`catch_unwind`]: `try_swait`
+/// never inspects the future's state after a panic, it only decides whether
+/// to keep polling, so the lack of a true `UnwindSafe` bound is not
+/// observable here. If a poll does panic, the thread-local signal's state is
+/// reset so it remains reusable for subsequent calls on this thread.
+///
+/// # Example
+///
+/// ```
+/// let my_fut = async {};
+/// let result = swait::try_swait(my_fut);
+/// assert_eq!(result.unwrap(), ());
+/// ```
+#[inline(always)]
+pub fn try_swait<F: Future>(fut: F) -> Result<F::Output, Box<dyn Any + Send>> {
+    let mut fut = pin!(fut);
+    THREAD_SIGNAL.with(|signal| {
+        let waker = Waker::from(Arc::clone(signal));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            let poll = catch_unwind(AssertUnwindSafe(|| fut.as_mut().poll(&mut context)));
+            match poll {
+                Ok(Poll::Pending) => signal.wait(),
+                Ok(Poll::Ready(result)) => return Ok(result),
+                Err(payload) => {
+                    // The poll unwound instead of returning normally; put the
+                    // signal back in a clean state so it can still be parked
+                    // on and notified by later calls on this thread.
+                    signal.state.store(WAITING, Ordering::Release);
+                    return Err(payload);
+                }
+            }
+        }
+    })
+}
+
+/// A minimal single-thread local executor: spawn detached `()`-output tasks
+/// and they'll progress alongside whatever future you block on with
+/// [`LocalPool::swait`].
+///
+/// Internally this is the same scheduled-flag-plus-waker scheme used by
+/// [`swait_all`] and [`swait_race`], just applied to a `RefCell`-guarded list
+/// of boxed tasks instead of a fixed set of futures, so tasks can be spawned
+/// while a `swait` call is already in progress.
+///
+/// # Example
+///
+/// ```
+/// use swait::LocalPool;
+///
+/// let pool = LocalPool::new();
+/// pool.spawn(async {
+///     // a helper task, e.g. a keep-alive ping
+/// });
+/// let result = pool.swait(async { 42 });
+/// assert_eq!(result, 42);
+/// ```
+#[derive(Default)]
+pub struct LocalPool {
+    tasks: RefCell<Vec<Task<dyn Future<Output = ()>>>>,
+}
+
+impl LocalPool {
+    /// Creates an empty local pool.
+    pub fn new() -> Self {
+        Self {
+            tasks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Spawns a detached task that is driven to completion by any future
+    /// [`LocalPool::swait`] call on this pool, running on the calling thread.
+    pub fn spawn(&self, future: impl Future<Output = ()> + 'static) {
+        THREAD_SIGNAL.with(|signal| {
+            let boxed: Pin<Box<dyn Future<Output = ()>>> = Box::pin(future);
+            self.tasks
+                .borrow_mut()
+                .push(Task::from_boxed(boxed, signal));
+        });
+    }
+
+    /// Blocks the current thread until `main` is ready, polling it every
+    /// iteration together with each spawned task that has been woken since
+    /// its last poll. Spawned tasks that complete
+    /// are dropped; if `main` resolves while spawned tasks remain, its output
+    /// is returned and the remaining tasks are left in the pool.
+    ///
+    /// The task list is drained into a local buffer before each task is
+    /// polled, rather than held borrowed across the poll: a task is free to
+    /// call [`LocalPool::spawn`] on this same pool from within its own poll
+    /// (e.g. to queue a follow-up task), and that reentrant `spawn` just
+    /// pushes onto the now-empty `RefCell` instead of hitting an
+    /// already-borrowed panic. Newly spawned tasks are merged back in once
+    /// the current round of polling finishes.
+    pub fn swait<F: Future>(&self, main: F) -> F::Output {
+        let mut main = pin!(main);
+        THREAD_SIGNAL.with(|signal| {
+            let main_waker = Waker::from(Arc::clone(signal));
+            let mut main_context = Context::from_waker(&main_waker);
+            loop {
+                if let Poll::Ready(result) = main.as_mut().poll(&mut main_context) {
+                    return result;
+                }
+
+                let mut any_scheduled = false;
+                let mut polling = std::mem::take(&mut *self.tasks.borrow_mut());
+                let mut index = 0;
+                while index < polling.len() {
+                    let waker = polling[index].waker.clone();
+                    let mut context = Context::from_waker(&waker);
+                    match polling[index].poll_if_scheduled(&mut context) {
+                        Some(Poll::Ready(())) => {
+                            any_scheduled = true;
+                            polling.remove(index);
+                        }
+                        Some(Poll::Pending) => {
+                            any_scheduled = true;
+                            index += 1;
+                        }
+                        None => index += 1,
+                    }
+                }
+
+                let mut tasks = self.tasks.borrow_mut();
+                polling.append(&mut tasks);
+                *tasks = polling;
+                drop(tasks);
+
+                if !any_scheduled {
+                    signal.wait();
+                }
+            }
+        })
+    }
+}
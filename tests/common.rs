@@ -1,7 +1,76 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
 use std::thread;
 use std::time::{Duration, Instant};
+
+use futures_core::Stream;
 use swait::*;
 
+// A future that resolves once `deadline` has passed, waking itself from a
+// background thread rather than blocking the polling thread. Used by tests
+// that need a future to genuinely yield `Poll::Pending` for a while instead
+// of completing synchronously inside a single poll call.
+struct Delay {
+    deadline: Instant,
+    waker_spawned: bool,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            waker_spawned: false,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        if !self.waker_spawned {
+            self.waker_spawned = true;
+            let waker = cx.waker().clone();
+            let deadline = self.deadline;
+            thread::spawn(move || {
+                let now = Instant::now();
+                if deadline > now {
+                    thread::sleep(deadline - now);
+                }
+                waker.wake();
+            });
+        }
+        Poll::Pending
+    }
+}
+
+// A minimal stream that yields the items of a `Vec` one poll at a time.
+struct VecStream<T> {
+    items: std::vec::IntoIter<T>,
+}
+
+impl<T> VecStream<T> {
+    fn new(items: Vec<T>) -> Self {
+        Self {
+            items: items.into_iter(),
+        }
+    }
+}
+
+impl<T: Unpin> Stream for VecStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Poll::Ready(self.get_mut().items.next())
+    }
+}
+
 // Test to ensure the basic functionality of swait works as expected
 #[test]
 fn test_swait_basic() {
@@ -39,3 +108,155 @@ fn test_swait_future_panic() {
     let future = async { panic!("Intentional panic for testing") };
     let _ = future.swait();
 }
+
+// Test to ensure swait_timeout returns the output when the future resolves in time
+#[test]
+fn test_swait_timeout_ready() {
+    let future = async { 42 };
+    let result = future.swait_timeout(Duration::from_millis(50));
+    assert_eq!(result, Some(42));
+}
+
+// Test to ensure swait_timeout returns None once the deadline elapses
+#[test]
+fn test_swait_timeout_expires() {
+    let future = async {
+        Delay::new(Duration::from_millis(100)).await;
+        42
+    };
+    let start = Instant::now();
+    let result = future.swait_timeout(Duration::from_millis(10));
+    let duration = start.elapsed();
+    assert_eq!(result, None);
+    assert!(duration < Duration::from_millis(100));
+}
+
+// A future that resolves to `value` after `ms` milliseconds without blocking
+// the polling thread, used to build `Vec`s of same-typed futures for
+// `swait_all`/`swait_race`.
+async fn delayed(ms: u64, value: i32) -> i32 {
+    Delay::new(Duration::from_millis(ms)).await;
+    value
+}
+
+// Test to ensure swait_all drives every future to completion, in order
+#[test]
+fn test_swait_all() {
+    let futures = vec![delayed(30, 1), delayed(0, 2), delayed(10, 3)];
+    let results = swait_all(futures);
+    assert_eq!(results, vec![1, 2, 3]);
+}
+
+// Test to ensure swait_join resolves both futures even if one completes later
+#[test]
+fn test_swait_join() {
+    let a = async {
+        thread::sleep(Duration::from_millis(20));
+        1
+    };
+    let b = async { "two" };
+    let (a, b) = swait_join(a, b);
+    assert_eq!(a, 1);
+    assert_eq!(b, "two");
+}
+
+// Test to ensure snext yields every item in order and then signals end-of-stream
+#[test]
+fn test_snext() {
+    let mut stream = VecStream::new(vec![1, 2, 3]);
+    assert_eq!(stream.snext(), Some(1));
+    assert_eq!(stream.snext(), Some(2));
+    assert_eq!(stream.snext(), Some(3));
+    assert_eq!(stream.snext(), None);
+}
+
+// Test to ensure swait_iter lets a stream be consumed with a plain for loop
+#[test]
+fn test_swait_iter() {
+    let stream = VecStream::new(vec![1, 2, 3]);
+    let collected: Vec<_> = stream.swait_iter().collect();
+    assert_eq!(collected, vec![1, 2, 3]);
+}
+
+// Test to ensure try_swait returns the output instead of panicking
+#[test]
+fn test_try_swait_ok() {
+    let future = async { 42 };
+    let result = future.try_swait();
+    assert_eq!(result.unwrap(), 42);
+}
+
+// Test to ensure try_swait catches a panicking future instead of unwinding
+#[test]
+fn test_try_swait_panic() {
+    let future = async { panic!("Intentional panic for testing") };
+    let result = future.try_swait();
+    assert!(result.is_err());
+}
+
+// Test to ensure the thread-local signal remains usable after try_swait catches a panic
+#[test]
+fn test_try_swait_panic_then_swait_again() {
+    let panicking = async { panic!("Intentional panic for testing") };
+    let _ = panicking.try_swait();
+
+    let future = async { 42 };
+    let result = future.swait();
+    assert_eq!(result, 42);
+}
+
+// Test to ensure swait_race returns the first future to resolve
+#[test]
+fn test_swait_race() {
+    let slow = delayed(30, 1);
+    let fast = delayed(0, 2);
+    let (winner, remaining) = swait_race(vec![slow, fast]);
+    assert_eq!(winner, 2);
+    assert_eq!(remaining.len(), 1);
+}
+
+// Test to ensure a spawned task actually progresses while the pool is
+// blocked on a longer-running main future, not just on already-ready ones
+#[test]
+fn test_local_pool_spawn_progresses_while_blocked() {
+    let pool = LocalPool::new();
+    let ran = Rc::new(Cell::new(false));
+
+    let ran_in_task = Rc::clone(&ran);
+    pool.spawn(async move {
+        Delay::new(Duration::from_millis(10)).await;
+        ran_in_task.set(true);
+    });
+
+    let result = pool.swait(async {
+        Delay::new(Duration::from_millis(50)).await;
+        42
+    });
+
+    assert_eq!(result, 42);
+    assert!(ran.get());
+}
+
+// Test to ensure a task can spawn a follow-up task on the same pool from
+// within its own poll without panicking on a reentrant RefCell borrow
+#[test]
+fn test_local_pool_spawn_from_within_task() {
+    let pool = Rc::new(LocalPool::new());
+    let ran = Rc::new(Cell::new(false));
+
+    let pool_in_task = Rc::clone(&pool);
+    let ran_in_followup = Rc::clone(&ran);
+    pool.spawn(async move {
+        pool_in_task.spawn(async move {
+            ran_in_followup.set(true);
+        });
+    });
+
+    let result = pool.swait(async {
+        Delay::new(Duration::from_millis(20)).await;
+        42
+    });
+
+    assert_eq!(result, 42);
+    assert!(ran.get());
+}